@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Result};
-use async_graphql::SimpleObject;
+use async_graphql::{Enum, SimpleObject};
 use async_trait::async_trait;
+use chrono::{Datelike, NaiveDate};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use surf::{http::headers::USER_AGENT, Client, Config, Url};
@@ -10,16 +11,27 @@ use crate::{
     graphql::{AUTHOR, PROJECT_NAME},
     migrator::{MetadataImageLot, MetadataLot, MetadataSource},
     miscellaneous::{
-        resolver::{MediaDetails, MediaSearchItem, MediaSearchResults},
+        resolver::{MediaDetails, MediaSearchItem},
         MediaSpecifics, MetadataCreator, MetadataImage, MetadataImageUrl, PAGE_LIMIT,
     },
-    models::media::AudioBookSpecifics,
-    traits::{MediaProvider, MediaProviderLanguages},
-    utils::{convert_date_to_year, convert_string_to_date, NamedObject},
+    models::media::{AudioBookSpecifics, PodcastEpisode, PodcastSpecifics},
+    traits::{ChartScope, MediaProvider, MediaProviderLanguages, PageCursor, Paginator},
+    utils::NamedObject,
 };
 
 pub static LOCALES: [&str; 10] = ["au", "ca", "de", "es", "fr", "in", "it", "jp", "gb", "us"];
 
+/// How much of a release date the source actually gave us. Audible returns
+/// dates in locale-specific formats and sometimes only a month or a year, so we
+/// keep the coarsest component that parsed to let the UI render "2023" vs.
+/// "March 2023" instead of fabricating a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum DatePrecision {
+    Year,
+    Month,
+    Day,
+}
+
 #[derive(Serialize, Deserialize)]
 struct PrimaryQuery {
     response_groups: String,
@@ -35,6 +47,7 @@ impl Default for PrimaryQuery {
                 "media",
                 "product_attrs",
                 "product_extended_attrs",
+                "relationships",
             ]
             .join(","),
             image_sizes: ["2400"].join(","),
@@ -52,6 +65,19 @@ struct SearchQuery {
     primary: PrimaryQuery,
 }
 
+#[derive(Serialize, Deserialize)]
+struct ChartsQuery {
+    num_results: i32,
+    page: i32,
+    products_sort_by: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    plan: Option<String>,
+    #[serde(flatten)]
+    primary: PrimaryQuery,
+}
+
 #[derive(Debug, Serialize, Deserialize, SimpleObject)]
 pub struct AudiblePoster {
     #[serde(rename = "2400")]
@@ -63,23 +89,60 @@ pub struct AudibleCategoryLadderCollection {
     ladder: Vec<NamedObject>,
 }
 
+#[derive(Debug, Serialize, Deserialize, SimpleObject)]
+pub struct AudibleRelationship {
+    asin: String,
+    sort: Option<String>,
+    content_delivery_type: Option<String>,
+    relationship_to_product: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, SimpleObject)]
 pub struct AudibleItem {
     asin: String,
     title: String,
     authors: Option<Vec<NamedObject>>,
     narrators: Option<Vec<NamedObject>>,
+    publisher_name: Option<String>,
     product_images: AudiblePoster,
     merchandising_summary: Option<String>,
     publisher_summary: Option<String>,
     release_date: Option<String>,
     runtime_length_min: Option<i32>,
+    content_type: Option<String>,
+    content_delivery_type: Option<String>,
+    relationships: Option<Vec<AudibleRelationship>>,
     category_ladders: Option<Vec<AudibleCategoryLadderCollection>>,
 }
 
+impl AudibleItem {
+    /// Audible marks podcast shows and episodes with a `content_delivery_type`
+    /// of `PodcastParent`/`PodcastEpisode` (and a `content_type` of `Podcast`),
+    /// while audiobooks leave these unset or use delivery types like
+    /// `SinglePartBook`. Map that onto the lot we track internally.
+    fn lot(&self) -> MetadataLot {
+        let is_podcast = self
+            .content_type
+            .as_deref()
+            .map(|c| c.eq_ignore_ascii_case("podcast"))
+            .unwrap_or(false)
+            || self
+                .content_delivery_type
+                .as_deref()
+                .map(|c| c.to_ascii_lowercase().contains("podcast"))
+                .unwrap_or(false);
+        if is_podcast {
+            MetadataLot::Podcast
+        } else {
+            MetadataLot::AudioBook
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AudibleService {
     client: Client,
+    locale: String,
 }
 
 impl MediaProviderLanguages for AudibleService {
@@ -97,7 +160,9 @@ impl AudibleService {
         let suffix = match locale {
             "us" => "com",
             "ca" => "ca",
-            "uk" => "co.uk",
+            // `LOCALES` lists the United Kingdom as `gb` while Audible's host is
+            // `co.uk`; accept both spellings so the two never diverge.
+            "uk" | "gb" => "co.uk",
             "au" => "co.au",
             "fr" => "fr",
             "de" => "de",
@@ -118,7 +183,82 @@ impl AudibleService {
             .set_base_url(Url::parse(&url).unwrap())
             .try_into()
             .unwrap();
-        Self { client }
+        Self {
+            client,
+            locale: config.locale.clone(),
+        }
+    }
+
+    /// Parse a raw Audible `release_date` into a date plus the precision we could
+    /// actually recover. We try the format the configured locale is known to use
+    /// first, then fall back to ISO `YYYY-MM-DD` and finally a bare `YYYY`,
+    /// returning the coarsest precision that matched and `None` only if nothing
+    /// parses.
+    fn parse_release_date(&self, raw: &str) -> Option<(NaiveDate, DatePrecision)> {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            return None;
+        }
+        // Full-date formats, most locale-specific first.
+        let day_formats: &[&str] = match self.locale.as_str() {
+            "us" => &["%m/%d/%Y", "%Y-%m-%d"],
+            "de" | "at" | "ch" => &["%d.%m.%Y", "%Y-%m-%d"],
+            "fr" | "es" | "it" => &["%d/%m/%Y", "%Y-%m-%d"],
+            _ => &["%Y-%m-%d", "%d/%m/%Y"],
+        };
+        for fmt in day_formats {
+            if let Ok(d) = NaiveDate::parse_from_str(raw, fmt) {
+                return Some((d, DatePrecision::Day));
+            }
+        }
+        // Month precision: chrono cannot parse a month/year into a `NaiveDate`
+        // on its own, so pin to the first of the month by prepending a day.
+        for (fmt, with_day) in [
+            ("%Y-%m-%d", format!("{}-01", raw)),
+            ("%d/%m/%Y", format!("01/{}", raw)),
+            ("%d.%m.%Y", format!("01.{}", raw)),
+        ] {
+            if let Ok(d) = NaiveDate::parse_from_str(&with_day, fmt) {
+                return Some((d, DatePrecision::Month));
+            }
+        }
+        // Year precision: pin to the first of the year.
+        if let Ok(year) = raw.parse::<i32>() {
+            if let Some(d) = NaiveDate::from_ymd_opt(year, 1, 1) {
+                return Some((d, DatePrecision::Year));
+            }
+        }
+        None
+    }
+
+    /// Fetch a single podcast episode product and map it onto `PodcastEpisode`,
+    /// keeping the `number` we derived from the parent's relationship ordering.
+    async fn podcast_episode(&self, asin: &str, number: i32) -> Result<PodcastEpisode> {
+        #[derive(Serialize, Deserialize, Debug)]
+        struct AudibleItemResponse {
+            product: AudibleItem,
+        }
+        let mut rsp = self
+            .client
+            .get(asin)
+            .query(&PrimaryQuery::default())
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let data: AudibleItemResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        let product = data.product;
+        let publish_date = self
+            .parse_release_date(&product.release_date.clone().unwrap_or_default())
+            .map(|(d, _)| d);
+        Ok(PodcastEpisode {
+            number,
+            id: product.asin,
+            title: product.title,
+            overview: product.publisher_summary.or(product.merchandising_summary),
+            thumbnail: product.product_images.image,
+            runtime: product.runtime_length_min,
+            publish_date,
+        })
     }
 }
 
@@ -137,11 +277,83 @@ impl MediaProvider for AudibleService {
             .await
             .map_err(|e| anyhow!(e))?;
         let data: AudibleItemResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
-        let d = self.audible_response_to_search_response(data.product);
+        let mut d = self.audible_response_to_search_response(data.product);
+        // For a podcast show the relationship list only gives us the child
+        // `asin`s; fetch each child product so the returned episodes carry a
+        // real title, runtime and release date rather than numbered blanks.
+        if let MediaSpecifics::Podcast(ref mut specifics) = d.specifics {
+            let mut episodes = Vec::with_capacity(specifics.episodes.len());
+            for stub in specifics.episodes.drain(..) {
+                episodes.push(match self.podcast_episode(&stub.id, stub.number).await {
+                    Ok(episode) => episode,
+                    Err(_) => stub,
+                });
+            }
+            specifics.episodes = episodes;
+        }
+        #[cfg(feature = "experimental-stabilizations")]
+        self.stabilize(&mut d);
         Ok(d)
     }
 
-    async fn search(&self, query: &str, page: Option<i32>) -> Result<MediaSearchResults> {
+    async fn charts(
+        &self,
+        category: Option<String>,
+        scope: ChartScope,
+        page: Option<i32>,
+    ) -> Result<Paginator<MediaSearchItem>> {
+        let page = page.unwrap_or(1);
+        #[derive(Serialize, Deserialize, Debug)]
+        struct AudibleChartsResponse {
+            total_results: i32,
+            products: Vec<AudibleItem>,
+        }
+        let mut rsp = self
+            .client
+            .get("")
+            .query(&ChartsQuery {
+                num_results: PAGE_LIMIT,
+                page: page - 1,
+                products_sort_by: "BestSellers".to_owned(),
+                category_id: category.clone(),
+                // A locale-scoped ranking is the storefront default; a global
+                // ranking asks Audible to aggregate sales across marketplaces.
+                plan: match scope {
+                    ChartScope::Locale => None,
+                    ChartScope::Global => Some("Global".to_owned()),
+                },
+                primary: PrimaryQuery::default(),
+            })
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let search: AudibleChartsResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        let items = search
+            .products
+            .into_iter()
+            .map(|d| self.to_search_item(d))
+            .collect::<Vec<_>>();
+        let next = self.next_cursor(
+            search.total_results,
+            page,
+            format!(
+                "charts\t{}\t{}\t{}",
+                match scope {
+                    ChartScope::Locale => "locale",
+                    ChartScope::Global => "global",
+                },
+                category.unwrap_or_default(),
+                page + 1
+            ),
+        );
+        Ok(Paginator {
+            total: search.total_results,
+            items,
+            next,
+        })
+    }
+
+    async fn search(&self, query: &str, page: Option<i32>) -> Result<Paginator<MediaSearchItem>> {
         let page = page.unwrap_or(1);
         #[derive(Serialize, Deserialize, Debug)]
         struct AudibleSearchResponse {
@@ -162,47 +374,159 @@ impl MediaProvider for AudibleService {
             .await
             .map_err(|e| anyhow!(e))?;
         let search: AudibleSearchResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
-        let resp = search
+        let items = search
             .products
             .into_iter()
-            .map(|d| {
-                let a = self.audible_response_to_search_response(d);
-                MediaSearchItem {
-                    identifier: a.identifier,
-                    lot: MetadataLot::AudioBook,
-                    title: a.title,
-                    images: a
-                        .images
-                        .into_iter()
-                        .map(|i| match i.url {
-                            MetadataImageUrl::S3(_u) => unreachable!(),
-                            MetadataImageUrl::Url(u) => u,
-                        })
-                        .collect(),
-                    publish_year: a.publish_year,
-                }
-            })
+            .map(|d| self.to_search_item(d))
             .collect::<Vec<_>>();
-        let next_page = if search.total_results - ((page) * PAGE_LIMIT) > 0 {
-            Some(page + 1)
-        } else {
-            None
-        };
-        Ok(MediaSearchResults {
+        let next = self.next_cursor(
+            search.total_results,
+            page,
+            format!("search\t{}\t{}", query, page + 1),
+        );
+        Ok(Paginator {
             total: search.total_results,
-            items: resp,
-            next_page,
+            items,
+            next,
         })
     }
+
+    async fn continuation(&self, token: &str) -> Result<Paginator<MediaSearchItem>> {
+        // Audible pages by numeric offset, so our cursor tokens carry the kind of
+        // listing, its parameter and the next page number. Decode and re-drive the
+        // matching endpoint to fetch that page.
+        let mut parts = token.split('\t');
+        match parts.next() {
+            Some("search") => {
+                let query = parts.next().ok_or_else(|| anyhow!("malformed token"))?;
+                let page = parts.next().and_then(|p| p.parse().ok());
+                self.search(query, page).await
+            }
+            Some("charts") => {
+                let scope = match parts.next() {
+                    Some("global") => ChartScope::Global,
+                    _ => ChartScope::Locale,
+                };
+                let category = parts
+                    .next()
+                    .filter(|c| !c.is_empty())
+                    .map(|c| c.to_owned());
+                let page = parts.next().and_then(|p| p.parse().ok());
+                self.charts(category, scope, page).await
+            }
+            _ => Err(anyhow!("malformed continuation token")),
+        }
+    }
+
+    #[cfg(feature = "experimental-stabilizations")]
+    fn stabilize(&self, details: &mut MediaDetails) {
+        // Strip the raw HTML Audible embeds in its publisher summaries.
+        if let Some(description) = details.description.as_mut() {
+            *description = strip_html(description);
+        }
+        // The same person is frequently credited as both author and narrator,
+        // which yields two `MetadataCreator` entries for one name. Collapse them,
+        // merging the roles into a single comma-separated entry.
+        let mut merged: Vec<MetadataCreator> = Vec::with_capacity(details.creators.len());
+        for creator in details.creators.drain(..) {
+            match merged.iter_mut().find(|c| c.name == creator.name) {
+                Some(existing) => {
+                    if !existing
+                        .role
+                        .split(", ")
+                        .any(|r| r == creator.role)
+                    {
+                        existing.role = format!("{}, {}", existing.role, creator.role);
+                    }
+                }
+                None => merged.push(creator),
+            }
+        }
+        details.creators = merged;
+        // Audible's catalog carries no language field, but each storefront is
+        // single-language, so infer it from the configured locale.
+        if details.language.is_none() {
+            details.language = Some(Self::locale_language(&self.locale).to_owned());
+        }
+    }
+
+    /// Best-effort ISO 639-1 language for an Audible storefront locale.
+    #[cfg(feature = "experimental-stabilizations")]
+    fn locale_language(locale: &str) -> &'static str {
+        match locale {
+            "de" => "de",
+            "fr" => "fr",
+            "it" => "it",
+            "es" => "es",
+            "jp" => "ja",
+            _ => "en",
+        }
+    }
+}
+
+/// Best-effort removal of HTML tags and a handful of common entities from a
+/// provider-supplied string. Kept deliberately simple — the
+/// `experimental-stabilizations` feature is opt-in heuristic cleanup, not a
+/// conformant HTML parser.
+#[cfg(feature = "experimental-stabilizations")]
+fn strip_html(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut in_tag = false;
+    for ch in input.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => out.push(ch),
+            _ => {}
+        }
+    }
+    out.replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .trim()
+        .to_owned()
 }
 
 impl AudibleService {
+    /// Map a catalog product onto a `MediaSearchItem`, shared by `search` and
+    /// `charts` so the poster-unwrap and lot/title plumbing live in one place.
+    fn to_search_item(&self, item: AudibleItem) -> MediaSearchItem {
+        let details = self.audible_response_to_search_response(item);
+        MediaSearchItem {
+            identifier: details.identifier,
+            lot: details.lot,
+            title: details.title,
+            images: details
+                .images
+                .into_iter()
+                .map(|i| match i.url {
+                    MetadataImageUrl::S3(_u) => unreachable!(),
+                    MetadataImageUrl::Url(u) => u,
+                })
+                .collect(),
+            publish_year: details.publish_year,
+        }
+    }
+
+    /// Build the cursor to the next page: Audible reports a `total_results`, so we
+    /// only hand back a token while unseen results remain.
+    fn next_cursor(&self, total_results: i32, page: i32, token: String) -> Option<PageCursor> {
+        if total_results - (page * PAGE_LIMIT) > 0 {
+            Some(PageCursor::Token(token))
+        } else {
+            None
+        }
+    }
+
     fn audible_response_to_search_response(&self, item: AudibleItem) -> MediaDetails {
         let images = Vec::from_iter(item.product_images.image.map(|a| MetadataImage {
             url: MetadataImageUrl::Url(a),
             lot: MetadataImageLot::Poster,
         }));
-        let release_date = item.release_date.unwrap_or_default();
+        let parsed_date = self.parse_release_date(&item.release_date.clone().unwrap_or_default());
         let mut creators = item
             .authors
             .unwrap_or_default()
@@ -223,10 +547,51 @@ impl AudibleService {
                     image_urls: vec![],
                 }),
         );
-        let description = item.publisher_summary.or(item.merchandising_summary);
+        let description = item
+            .publisher_summary
+            .clone()
+            .or_else(|| item.merchandising_summary.clone());
+        let lot = item.lot();
+        let specifics = match lot {
+            MetadataLot::Podcast => {
+                let episodes = item
+                    .relationships
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|r| {
+                        r.relationship_to_product.as_deref() == Some("child")
+                            || r.content_delivery_type
+                                .as_deref()
+                                .map(|c| c.eq_ignore_ascii_case("PodcastEpisode"))
+                                .unwrap_or(false)
+                    })
+                    .enumerate()
+                    .map(|(idx, r)| PodcastEpisode {
+                        number: r
+                            .sort
+                            .and_then(|s| s.parse().ok())
+                            .unwrap_or((idx + 1) as i32),
+                        id: r.asin,
+                        title: String::new(),
+                        overview: None,
+                        thumbnail: None,
+                        runtime: None,
+                        publish_date: None,
+                    })
+                    .collect::<Vec<_>>();
+                MediaSpecifics::Podcast(PodcastSpecifics {
+                    total_episodes: episodes.len() as i32,
+                    publisher: item.publisher_name,
+                    episodes,
+                })
+            }
+            _ => MediaSpecifics::AudioBook(AudioBookSpecifics {
+                runtime: item.runtime_length_min,
+            }),
+        };
         MediaDetails {
             identifier: item.asin,
-            lot: MetadataLot::AudioBook,
+            lot,
             source: MetadataSource::Audible,
             title: item.title,
             description,
@@ -238,11 +603,11 @@ impl AudibleService {
                 .flat_map(|c| c.ladder.into_iter().map(|l| l.name))
                 .unique()
                 .collect(),
-            publish_year: convert_date_to_year(&release_date),
-            publish_date: convert_string_to_date(&release_date),
-            specifics: MediaSpecifics::AudioBook(AudioBookSpecifics {
-                runtime: item.runtime_length_min,
-            }),
+            publish_year: parsed_date.map(|(d, _)| d.year()),
+            publish_date: parsed_date.map(|(d, _)| d),
+            publish_date_precision: parsed_date.map(|(_, p)| p),
+            language: None,
+            specifics,
             images,
         }
     }
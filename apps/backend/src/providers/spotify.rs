@@ -0,0 +1,368 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use async_std::sync::Mutex;
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+use surf::{
+    http::{headers::AUTHORIZATION, mime},
+    Client, Config, Url,
+};
+
+use crate::{
+    config::SpotifyConfig,
+    migrator::{MetadataImageLot, MetadataLot, MetadataSource},
+    miscellaneous::{
+        resolver::{MediaDetails, MediaSearchItem},
+        MediaSpecifics, MetadataCreator, MetadataImage, MetadataImageUrl, PAGE_LIMIT,
+    },
+    models::media::MusicSpecifics,
+    providers::audible::DatePrecision,
+    traits::{MediaProvider, MediaProviderLanguages, PageCursor, Paginator},
+    utils::{convert_date_to_year, convert_string_to_date},
+};
+
+static URL: &str = "https://api.spotify.com/v1/";
+static TOKEN_URL: &str = "https://accounts.spotify.com/api/token";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SpotifyImage {
+    url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SpotifyArtist {
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SpotifyAlbum {
+    id: String,
+    name: String,
+    #[serde(default)]
+    images: Vec<SpotifyImage>,
+    #[serde(default)]
+    artists: Vec<SpotifyArtist>,
+    release_date: Option<String>,
+    release_date_precision: Option<String>,
+    total_tracks: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SpotifyExternalIds {
+    isrc: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SpotifyTrack {
+    id: String,
+    name: String,
+    #[serde(default)]
+    artists: Vec<SpotifyArtist>,
+    album: Option<SpotifyAlbum>,
+    duration_ms: Option<i32>,
+    track_number: Option<i32>,
+    disc_number: Option<i32>,
+    external_ids: Option<SpotifyExternalIds>,
+}
+
+/// Cached client-credentials bearer token plus the Unix timestamp (seconds) at
+/// which it expires, so we only hit the token endpoint when necessary.
+#[derive(Debug, Clone, Default)]
+struct CachedToken {
+    access_token: String,
+    expires_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpotifyService {
+    client: Client,
+    client_id: String,
+    client_secret: String,
+    token: Arc<Mutex<CachedToken>>,
+}
+
+impl MediaProviderLanguages for SpotifyService {
+    fn supported_languages() -> Vec<String> {
+        ["us"].into_iter().map(String::from).collect()
+    }
+
+    fn default_language() -> String {
+        "us".to_owned()
+    }
+}
+
+impl SpotifyService {
+    pub fn new(config: &SpotifyConfig) -> Self {
+        let client = Config::new()
+            .set_base_url(Url::parse(URL).unwrap())
+            .try_into()
+            .unwrap();
+        Self {
+            client,
+            client_id: config.client_id.clone(),
+            client_secret: config.client_secret.clone(),
+            token: Arc::new(Mutex::new(CachedToken::default())),
+        }
+    }
+
+    /// Exchange the configured client credentials for a bearer token, reusing a
+    /// cached one until it is within a minute of expiring.
+    async fn access_token(&self) -> Result<String> {
+        let mut token = self.token.lock().await;
+        let now = chrono::Utc::now().timestamp();
+        if !token.access_token.is_empty() && token.expires_at - 60 > now {
+            return Ok(token.access_token.clone());
+        }
+        #[derive(Serialize)]
+        struct TokenRequest {
+            grant_type: String,
+        }
+        #[derive(Deserialize)]
+        struct TokenResponse {
+            access_token: String,
+            expires_in: i64,
+        }
+        let basic = BASE64.encode(format!("{}:{}", self.client_id, self.client_secret));
+        let mut rsp = surf::post(TOKEN_URL)
+            .header(AUTHORIZATION, format!("Basic {}", basic))
+            .content_type(mime::FORM)
+            .body(
+                surf::Body::from_form(&TokenRequest {
+                    grant_type: "client_credentials".to_owned(),
+                })
+                .map_err(|e| anyhow!(e))?,
+            )
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let data: TokenResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        token.access_token = data.access_token.clone();
+        token.expires_at = now + data.expires_in;
+        Ok(data.access_token)
+    }
+
+    fn album_to_details(&self, album: SpotifyAlbum) -> MediaDetails {
+        let release_date = album.release_date.unwrap_or_default();
+        let precision = precision_from_spotify(album.release_date_precision.as_deref());
+        let creators = album
+            .artists
+            .into_iter()
+            .map(|a| MetadataCreator {
+                name: a.name,
+                role: "Album Artist".to_owned(),
+                image_urls: vec![],
+            })
+            .collect();
+        let images = album
+            .images
+            .into_iter()
+            .map(|i| MetadataImage {
+                url: MetadataImageUrl::Url(i.url),
+                lot: MetadataImageLot::Poster,
+            })
+            .collect();
+        MediaDetails {
+            identifier: album.id,
+            lot: MetadataLot::Music,
+            source: MetadataSource::Spotify,
+            title: album.name,
+            description: None,
+            creators,
+            genres: vec![],
+            publish_year: convert_date_to_year(&release_date),
+            publish_date: convert_string_to_date(&release_date),
+            publish_date_precision: precision,
+            language: None,
+            specifics: MediaSpecifics::Music(MusicSpecifics {
+                is_album: true,
+                duration: None,
+                track_number: None,
+                disc_number: None,
+                isrc: None,
+                total_tracks: album.total_tracks,
+            }),
+            images,
+        }
+    }
+
+    fn track_to_details(&self, track: SpotifyTrack) -> MediaDetails {
+        let album = track.album;
+        let release_date = album
+            .as_ref()
+            .and_then(|a| a.release_date.clone())
+            .unwrap_or_default();
+        let precision = album
+            .as_ref()
+            .and_then(|a| precision_from_spotify(a.release_date_precision.as_deref()));
+        let mut creators = track
+            .artists
+            .into_iter()
+            .map(|a| MetadataCreator {
+                name: a.name,
+                role: "Artist".to_owned(),
+                image_urls: vec![],
+            })
+            .collect::<Vec<_>>();
+        if let Some(album) = album.as_ref() {
+            creators.extend(album.artists.iter().map(|a| MetadataCreator {
+                name: a.name.clone(),
+                role: "Album Artist".to_owned(),
+                image_urls: vec![],
+            }));
+        }
+        let images = album
+            .map(|a| a.images)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|i| MetadataImage {
+                url: MetadataImageUrl::Url(i.url),
+                lot: MetadataImageLot::Poster,
+            })
+            .collect();
+        MediaDetails {
+            identifier: track.id,
+            lot: MetadataLot::Music,
+            source: MetadataSource::Spotify,
+            title: track.name,
+            description: None,
+            creators,
+            genres: vec![],
+            publish_year: convert_date_to_year(&release_date),
+            publish_date: convert_string_to_date(&release_date),
+            publish_date_precision: precision,
+            language: None,
+            specifics: MediaSpecifics::Music(MusicSpecifics {
+                is_album: false,
+                duration: track.duration_ms.map(|d| d / 1000),
+                track_number: track.track_number,
+                disc_number: track.disc_number,
+                isrc: track.external_ids.and_then(|e| e.isrc),
+                total_tracks: None,
+            }),
+            images,
+        }
+    }
+}
+
+/// Map Spotify's `release_date_precision` string onto our shared `DatePrecision`.
+fn precision_from_spotify(precision: Option<&str>) -> Option<DatePrecision> {
+    match precision {
+        Some("day") => Some(DatePrecision::Day),
+        Some("month") => Some(DatePrecision::Month),
+        Some("year") => Some(DatePrecision::Year),
+        _ => None,
+    }
+}
+
+#[async_trait]
+impl MediaProvider for SpotifyService {
+    async fn details(&self, identifier: &str) -> Result<MediaDetails> {
+        // Album identifiers are prefixed so a single `details` entry point can
+        // resolve either side of the album/track hierarchy.
+        let token = self.access_token().await?;
+        if let Some(id) = identifier.strip_prefix("album:") {
+            let mut rsp = self
+                .client
+                .get(format!("albums/{}", id))
+                .header(AUTHORIZATION, format!("Bearer {}", token))
+                .await
+                .map_err(|e| anyhow!(e))?;
+            let album: SpotifyAlbum = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+            Ok(self.album_to_details(album))
+        } else {
+            let id = identifier.strip_prefix("track:").unwrap_or(identifier);
+            let mut rsp = self
+                .client
+                .get(format!("tracks/{}", id))
+                .header(AUTHORIZATION, format!("Bearer {}", token))
+                .await
+                .map_err(|e| anyhow!(e))?;
+            let track: SpotifyTrack = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+            Ok(self.track_to_details(track))
+        }
+    }
+
+    async fn search(&self, query: &str, page: Option<i32>) -> Result<Paginator<MediaSearchItem>> {
+        let page = page.unwrap_or(1);
+        let token = self.access_token().await?;
+        #[derive(Serialize)]
+        struct SearchQuery {
+            q: String,
+            r#type: String,
+            limit: i32,
+            offset: i32,
+        }
+        #[derive(Deserialize)]
+        struct Paged<T> {
+            items: Vec<T>,
+            total: i32,
+        }
+        #[derive(Deserialize)]
+        struct SpotifySearchResponse {
+            albums: Paged<SpotifyAlbum>,
+            tracks: Paged<SpotifyTrack>,
+        }
+        let mut rsp = self
+            .client
+            .get("search")
+            .query(&SearchQuery {
+                q: query.to_owned(),
+                r#type: "album,track".to_owned(),
+                limit: PAGE_LIMIT,
+                offset: (page - 1) * PAGE_LIMIT,
+            })
+            .unwrap()
+            .header(AUTHORIZATION, format!("Bearer {}", token))
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let search: SpotifySearchResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        let total = search.albums.total + search.tracks.total;
+        // Albums carry an `album:` prefix and tracks a `track:` one so `details`
+        // can route either identifier back to the right endpoint.
+        let items = search
+            .albums
+            .items
+            .into_iter()
+            .map(|a| (format!("album:{}", a.id), self.album_to_details(a)))
+            .chain(
+                search
+                    .tracks
+                    .items
+                    .into_iter()
+                    .map(|t| (format!("track:{}", t.id), self.track_to_details(t))),
+            )
+            .map(|(identifier, d)| MediaSearchItem {
+                identifier,
+                lot: MetadataLot::Music,
+                title: d.title,
+                images: d
+                    .images
+                    .into_iter()
+                    .map(|i| match i.url {
+                        MetadataImageUrl::S3(_u) => unreachable!(),
+                        MetadataImageUrl::Url(u) => u,
+                    })
+                    .collect(),
+                publish_year: d.publish_year,
+            })
+            .collect::<Vec<_>>();
+        let next = if total - (page * PAGE_LIMIT) > 0 {
+            Some(PageCursor::Token(format!("{}\t{}", query, page + 1)))
+        } else {
+            None
+        };
+        Ok(Paginator {
+            total,
+            items,
+            next,
+        })
+    }
+
+    async fn continuation(&self, token: &str) -> Result<Paginator<MediaSearchItem>> {
+        let (query, page) = token
+            .split_once('\t')
+            .ok_or_else(|| anyhow!("malformed continuation token"))?;
+        self.search(query, page.parse().ok()).await
+    }
+}
@@ -0,0 +1,89 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::miscellaneous::resolver::{MediaDetails, MediaSearchItem};
+
+pub trait MediaProviderLanguages {
+    fn supported_languages() -> Vec<String>;
+    fn default_language() -> String;
+}
+
+/// Where the *next* page of a [`Paginator`] lives: either a bare page number
+/// ([`PageCursor::Page`]) or an opaque token ([`PageCursor::Token`]) that the
+/// issuing provider's [`MediaProvider::continuation`] decodes. A token can carry
+/// everything needed to re-drive an offset-paged endpoint (the kind of listing,
+/// its parameters and the next page number), so offset- and token-paged
+/// providers alike hand back a `Token` that callers feed to `continuation`
+/// until `next` is `None`.
+#[derive(Debug, Clone)]
+pub enum PageCursor {
+    Page(i32),
+    Token(String),
+}
+
+/// Which ranking a [`MediaProvider::charts`] call should return: the ranking for
+/// the provider's configured storefront/locale, or a single aggregate across
+/// every locale the provider serves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartScope {
+    Locale,
+    Global,
+}
+
+impl Default for ChartScope {
+    fn default() -> Self {
+        Self::Locale
+    }
+}
+
+/// A single page of provider results plus a cursor to the next one, replacing
+/// the old `MediaSearchResults { next_page }` shape so offset- and token-paged
+/// providers can be iterated uniformly.
+#[derive(Debug, Clone)]
+pub struct Paginator<T> {
+    pub total: i32,
+    pub items: Vec<T>,
+    pub next: Option<PageCursor>,
+}
+
+impl<T> Paginator<T> {
+    /// Whether another page can be fetched via [`MediaProvider::continuation`].
+    pub fn has_more(&self) -> bool {
+        self.next.is_some()
+    }
+}
+
+#[async_trait]
+pub trait MediaProvider {
+    /// Fetch the full details for a single item by its provider identifier.
+    async fn details(&self, identifier: &str) -> Result<MediaDetails>;
+
+    /// Search the provider's catalog for `query`, returning the requested page
+    /// (the first when `page` is `None`) plus a cursor to the next one.
+    async fn search(&self, query: &str, page: Option<i32>) -> Result<Paginator<MediaSearchItem>>;
+
+    /// Fetch the page pointed at by a cursor token previously returned in a
+    /// [`Paginator::next`]. Providers that cannot resume from a token fall back
+    /// to the default.
+    async fn continuation(&self, _token: &str) -> Result<Paginator<MediaSearchItem>> {
+        unimplemented!("this provider does not support continuation paging")
+    }
+
+    /// Browse a best-seller / charts ranking without a search term, optionally
+    /// scoped to a category. Providers that cannot rank their catalog fall back
+    /// to the default.
+    async fn charts(
+        &self,
+        _category: Option<String>,
+        _scope: ChartScope,
+        _page: Option<i32>,
+    ) -> Result<Paginator<MediaSearchItem>> {
+        unimplemented!("this provider does not support charts browsing")
+    }
+
+    /// Opt-in, best-effort cleanup of freshly parsed details (HTML stripping,
+    /// creator de-duplication, language inference, ...). Gated behind
+    /// `experimental-stabilizations` so the stable parsing path stays untouched.
+    #[cfg(feature = "experimental-stabilizations")]
+    fn stabilize(&self, _details: &mut MediaDetails) {}
+}